@@ -1,14 +1,19 @@
 use crate::app::event_loop::Message;
 use crate::app::settings::{Column, Settings};
 use crate::data::schema::Schema;
+use crate::data::series::Slice;
 use crate::platform::exec::spawned_stdout;
 
+use csv_core::{ReadRecordResult, Reader as CoreReader, ReaderBuilder as CoreReaderBuilder};
+
 use std::io::stdin;
 use std::io::BufRead;
 use std::io::BufReader;
 use std::io::Read;
+use std::net::TcpStream;
 use std::sync::mpsc;
 use std::thread;
+use std::time::Duration;
 
 /// FetcherLoop is responsbile for setting up and maintaining
 /// communication channel between main loop and data reading routines
@@ -52,8 +57,10 @@ enum FetchMode {
 
 struct Fetcher {
     cmd: Option<String>,
+    tcp: Option<String>,
     x: Column,
     epoch: Column,
+    conversions: Vec<String>,
     sender_to_main_loop: mpsc::Sender<Message>,
     mode: FetchMode,
 }
@@ -62,8 +69,10 @@ impl Fetcher {
     pub fn new(settings: &Settings, sender_to_main_loop: mpsc::Sender<Message>) -> Fetcher {
         Fetcher {
             cmd: settings.cmd.as_ref().map(|v| v.join(" ")),
+            tcp: settings.tcp.clone(),
             x: settings.x.clone(),
             epoch: settings.epoch.clone(),
+            conversions: settings.conversions.clone(),
             sender_to_main_loop: sender_to_main_loop.clone(),
             mode : match (settings.refresh_rate.as_nanos() > 0, settings.epoch != Column::None) {
                 (true, _) => FetchMode::Autorefresh,
@@ -85,81 +94,182 @@ impl Fetcher {
     pub fn read(&self) -> Result<(), FetcherError> {
         if let Some(cmd) = self.cmd.as_ref() {
             self.read_from(spawned_stdout(&cmd)?)
+        } else if let Some(addr) = self.tcp.as_ref() {
+            self.read_tcp(addr)
         } else {
             let stdin = stdin();
-            self.read_from(stdin.lock()) 
+            self.read_from(stdin.lock())
+        }
+    }
+
+    /// Connects to a `host:port` TCP endpoint and runs the regular
+    /// `read_from` pipeline over the connection, exactly as with a
+    /// spawned command's stdout or stdin. If the connection ends --
+    /// whether cleanly, with a transient IO error such as a reset or
+    /// timeout, or the initial connect failing outright (e.g. the peer
+    /// isn't listening yet) -- reconnects and resumes, starting a fresh
+    /// data set for each new connection. A short backoff between
+    /// attempts keeps a peer that refuses, or accepts and immediately
+    /// closes, from causing a busy reconnect loop. A malformed-data
+    /// (`FetcherError::CSV`) error is not a connectivity problem, so
+    /// it's propagated instead of retried.
+    fn read_tcp(&self, addr: &str) -> Result<(), FetcherError> {
+        const RECONNECT_BACKOFF: Duration = Duration::from_millis(250);
+
+        loop {
+            let result = TcpStream::connect(addr)
+                .map_err(FetcherError::from)
+                .and_then(|stream| self.read_from(stream));
+            match result {
+                Ok(()) | Err(FetcherError::IO(_)) => thread::sleep(RECONNECT_BACKOFF),
+                // In Autorefresh mode the connection is read through a
+                // `csv::Reader`, so a reset or timeout arrives wrapped as
+                // a CSV error.
+                Err(FetcherError::CSV(ref e)) if e.is_io_error() => {
+                    thread::sleep(RECONNECT_BACKOFF)
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Builds a CSV reader over a whole input stream, honouring quoting,
+    /// escaping, surrounding whitespace and embedded newlines within a
+    /// quoted field, instead of naively splitting on commas (or on `\n`
+    /// ahead of parsing, which would tear a quoted multi-line field in
+    /// two). This is what lets `FetcherError::CSV` actually surface.
+    fn build_reader<R: Read>(reader: R) -> csv::Reader<R> {
+        csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(reader)
+    }
+
+    /// Reports how many fields in the last slice failed their configured
+    /// conversion, so a parse failure shows up in the UI instead of
+    /// silently becoming a NaN.
+    fn report_conversion_failures(&self, failures: usize) {
+        if failures > 0 {
+            self.sender_to_main_loop
+                .send(Message::ConversionError(failures))
+                .unwrap();
         }
     }
 
-    // reading in batches, flush/quit on EOF, flush on empty line.
+    /// Reading in batches, flush/quit on EOF, flush on an empty line.
+    ///
+    /// `csv::Reader`'s own record iterator has no concept of a blank
+    /// line -- with `flexible(true)` it's just a zero-width record, and
+    /// gets silently skipped rather than yielded, so it can't be used to
+    /// tell a batch separator apart from ordinary data. The separator is
+    /// therefore found at the line level instead, by grouping raw lines
+    /// into a batch up to (but not including) the next blank one, and
+    /// only then handing that batch's joined text to a single CSV reader
+    /// -- one CSV reader per batch, not per row, and still with proper
+    /// quoting/escaping within a batch. The one thing this can't handle
+    /// is a quoted field that itself contains a blank line; that's
+    /// indistinguishable from a real batch separator at this level.
     fn read_batches(&self, reader: impl Read) -> Result<(), FetcherError> {
-        let reader = BufReader::new(reader);
+        let mut lines = BufReader::new(reader).lines();
 
         // each iteration of a loop is a new batch/epoch
-        let mut lines = reader.lines();
-        while let Some(l) = lines.next() {
-            let schema = Schema::new(self.x.clone(), self.epoch.clone(), l?.split(','));
-            let mut data = schema.empty_set();
+        while let Some(first_line) = lines.next() {
+            let mut batch = first_line?;
+            batch.push('\n');
 
             loop {
                 match lines.next() {
-                    // This arm is 'regular data'
-                    Some(Ok(l)) if l != "" => data.append_slice(schema.slice(l.split(','))),
-                    // This arm is EOF or empty line
-                    _ => {
-                        self.sender_to_main_loop.send(Message::AppendDataSet(data)).unwrap();
-                        break;
+                    Some(Ok(line)) if !line.is_empty() => {
+                        batch.push_str(&line);
+                        batch.push('\n');
                     }
+                    Some(Err(e)) => return Err(e.into()),
+                    // This arm is EOF or an empty-line batch separator
+                    _ => break,
                 }
             }
+
+            let mut records = Self::build_reader(batch.as_bytes()).into_records();
+            let titles = match records.next() {
+                Some(titles) => titles?,
+                None => continue,
+            };
+            let schema = Schema::new(self.x.clone(), self.epoch.clone(), &self.conversions, &titles);
+            let mut data = schema.empty_set();
+            // Reused across every row in this batch to avoid a
+            // per-row allocation; sized to the column count, not the
+            // batch's row count (the per-series vectors `data` grows
+            // into via `append_slice` aren't pre-sized here).
+            let mut scratch = Slice::default();
+            scratch.y.reserve(schema.y_len());
+
+            for record in records {
+                let failures = schema.slice_into(&record?, &mut scratch);
+                self.report_conversion_failures(failures);
+                data.append_slice(&scratch);
+            }
+            self.sender_to_main_loop.send(Message::AppendDataSet(data)).unwrap();
         }
 
         Ok(())
     }
 
-    /// Reading lines one by one, sending over as we go.
-    fn read_lines(&self, reader: impl Read) -> Result<(), FetcherError> {
-        let reader = BufReader::new(reader);
-
-        // each iteration of a loop is a new batch/epoch
-        let mut lines = reader.lines();
-        while let Some(l) = lines.next() {
-            // TODO: no clone
-            let schema = Schema::new(self.x.clone(), self.epoch.clone(), l?.split(','));
-            self.sender_to_main_loop
-                .send(Message::Data(schema.empty_set()))
-                .unwrap();
+    /// Reading lines one by one, sending over as we go. Driven by the
+    /// push-based `IncrementalDecoder` so a slow/chatty producer doesn't
+    /// force us to wait on a full `\n`-terminated line before reacting.
+    fn read_lines(&self, mut reader: impl Read) -> Result<(), FetcherError> {
+        let mut decoder = IncrementalDecoder::new(
+            self.x.clone(),
+            self.epoch.clone(),
+            self.conversions.clone(),
+            self.sender_to_main_loop.clone(),
+        );
+        let mut buf = vec![0u8; 8192];
+        let mut pending = 0;
 
-            loop {
-                match lines.next() {
-                    // This arm is 'regular data'
-                    Some(Ok(l)) if l != "" => self
-                        .sender_to_main_loop
-                        .send(Message::DataSlice(schema.slice(l.split(','))))
-                        .unwrap(),
-                    // This arm is EOF or empty line
-                    _ => {
-                        break;
-                    }
-                }
+        loop {
+            if pending == buf.len() {
+                let len = buf.len();
+                buf.resize(len * 2, 0);
             }
+            let n = reader.read(&mut buf[pending..])?;
+            if n == 0 {
+                break;
+            }
+            let available = pending + n;
+            let (_, consumed) = decoder.decode(&buf[..available]);
+            buf.copy_within(consumed..available, 0);
+            pending = available - consumed;
         }
 
+        // A trailing record with no terminating newline is held inside
+        // the core decoder's internal state until an explicit EOF signal
+        // (an empty input slice) releases it.
+        decoder.decode(&[]);
+
         Ok(())
     }
 
     // reads until EOF, sends single update
     fn read_all(&self, reader: impl Read) -> Result<(), FetcherError> {
-        let reader = BufReader::new(reader);
+        let mut records = Self::build_reader(reader).into_records();
 
-        // each iteration of a loop is a new batch/epoch
-        let mut lines = reader.lines();
-        if let Some(l) = lines.next() {
-            let schema = Schema::new(self.x.clone(), self.epoch.clone(), l?.split(','));
+        if let Some(first) = records.next() {
+            let first = first?;
+            let schema = Schema::new(self.x.clone(), self.epoch.clone(), &self.conversions, &first);
             let mut data = schema.empty_set();
+            // Reused across every row in this batch to avoid a
+            // per-row allocation; sized to the column count, not the
+            // batch's row count (the per-series vectors `data` grows
+            // into via `append_slice` aren't pre-sized here).
+            let mut scratch = Slice::default();
+            scratch.y.reserve(schema.y_len());
 
-            for l in lines {
-                data.append_slice(schema.slice(l?.split(',')));
+            for record in records {
+                let failures = schema.slice_into(&record?, &mut scratch);
+                self.report_conversion_failures(failures);
+                data.append_slice(&scratch);
             }
             self.sender_to_main_loop.send(Message::Data(data)).unwrap();
         }
@@ -168,6 +278,484 @@ impl Fetcher {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_reader_keeps_quoted_embedded_newline_as_one_record() {
+        // A quoted field spanning a literal newline must stay one
+        // record, not be torn in two the way splitting on `\n` ahead of
+        // CSV parsing would.
+        let input = "a,b\n1,\"line one\nline two\"\n";
+        let mut records = Fetcher::build_reader(input.as_bytes()).into_records();
+
+        let header = records.next().unwrap().unwrap();
+        assert_eq!(header.iter().collect::<Vec<_>>(), vec!["a", "b"]);
+
+        let row = records.next().unwrap().unwrap();
+        assert_eq!(row.get(0), Some("1"));
+        assert_eq!(row.get(1), Some("line one\nline two"));
+
+        assert!(records.next().is_none());
+    }
+
+    fn batch_fetcher() -> (Fetcher, mpsc::Receiver<Message>) {
+        let (sender, receiver) = mpsc::channel();
+        let fetcher = Fetcher {
+            cmd: None,
+            tcp: None,
+            x: Column::None,
+            epoch: Column::None,
+            conversions: vec![],
+            sender_to_main_loop: sender,
+            mode: FetchMode::Batch,
+        };
+        (fetcher, receiver)
+    }
+
+    #[test]
+    fn read_batches_splits_on_a_blank_line() {
+        let (fetcher, receiver) = batch_fetcher();
+        fetcher
+            .read_batches("a,b\n1,2\n\nc,d,e\n3,4,5\n".as_bytes())
+            .unwrap();
+
+        let messages: Vec<_> = receiver.try_iter().collect();
+        assert_eq!(messages.len(), 2);
+        match (&messages[0], &messages[1]) {
+            (Message::AppendDataSet(first), Message::AppendDataSet(second)) => {
+                assert_eq!(first.y.len(), 2);
+                assert_eq!(second.y.len(), 3);
+            }
+            _ => panic!("expected two AppendDataSet messages"),
+        }
+    }
+
+    #[test]
+    fn read_batches_quotes_commas_within_a_batch() {
+        // A quoted comma inside a batch must stay part of one field
+        // rather than splitting into an extra column.
+        let (fetcher, receiver) = batch_fetcher();
+        fetcher
+            .read_batches("a,\"b, with a comma\",c\n1,2,3\n".as_bytes())
+            .unwrap();
+
+        let sets: Vec<_> = receiver
+            .try_iter()
+            .filter_map(|m| match m {
+                Message::AppendDataSet(set) => Some(set),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(sets.len(), 1);
+        assert_eq!(sets[0].y.len(), 3);
+    }
+}
+
+/// Push-based incremental CSV decoder for the `Incremental` fetch mode.
+///
+/// Unlike `BufReader::lines()`, this never blocks waiting for a full
+/// `\n`-terminated line: the caller feeds it whatever bytes are
+/// currently available via `decode`, and it emits a `Message::Data`/
+/// `Message::DataSlice` for each complete record the underlying
+/// `csv_core::Reader` yields, holding a partial trailing record inside
+/// the decoder until a later call completes it. Its output buffers are reused
+/// across records rather than reallocating a `String` per line.
+struct IncrementalDecoder {
+    core: CoreReader,
+    field_buf: Vec<u8>,
+    ends_buf: Vec<usize>,
+    schema: Option<Schema>,
+    x: Column,
+    epoch: Column,
+    conversions: Vec<String>,
+    sender_to_main_loop: mpsc::Sender<Message>,
+    // Last byte fed to `core` across calls to `decode`, so a blank-line
+    // separator split across two calls (one ending in `\n`, the next
+    // starting with `\n`) is still detected. Starts as `\n` so a stream
+    // that opens with a blank line is handled the same way.
+    last_byte: u8,
+    // Set by `track_blank_lines` when a `\n\n` boundary has been seen
+    // since the last record; applied (and cleared) by `emit` right
+    // before the next record derives/reuses a schema.
+    pending_reset: bool,
+    // Bytes/ends already written into `field_buf`/`ends_buf` for the
+    // record in progress; kept across `decode` calls since a record can
+    // span them.
+    outlen: usize,
+    endlen: usize,
+}
+
+impl IncrementalDecoder {
+    fn new(
+        x: Column,
+        epoch: Column,
+        conversions: Vec<String>,
+        sender_to_main_loop: mpsc::Sender<Message>,
+    ) -> IncrementalDecoder {
+        IncrementalDecoder {
+            core: CoreReaderBuilder::new().build(),
+            field_buf: vec![0; 4096],
+            ends_buf: vec![0; 64],
+            schema: None,
+            x,
+            epoch,
+            conversions,
+            sender_to_main_loop,
+            last_byte: b'\n',
+            pending_reset: false,
+            outlen: 0,
+            endlen: 0,
+        }
+    }
+
+    /// Decodes as many complete records as `input` currently contains,
+    /// emitting one `Message` per record. Returns the number of records
+    /// emitted and the number of bytes of `input` consumed. A record
+    /// still in progress at the end of `input` is held inside the
+    /// decoder, so all of `input` is consumed in practice; any unconsumed
+    /// bytes should be kept at the front of the caller's buffer for the
+    /// next call.
+    fn decode(&mut self, input: &[u8]) -> (usize, usize) {
+        let mut consumed = 0;
+        let mut records = 0;
+        // `self.outlen`/`self.endlen` count the cumulative bytes/ends
+        // written into `field_buf`/`ends_buf` for the record currently
+        // being assembled, across calls. `core.read_record` only
+        // fills the slice it's handed starting from its own index 0, so
+        // a retry after `OutputFull`/`OutputEndsFull` must be handed
+        // `&mut field_buf[outlen..]`/`&mut ends_buf[endlen..]` rather
+        // than the buffers from the start -- otherwise it overwrites the
+        // bytes/ends a previous call already wrote for this same record.
+        // Mirrors `csv::Reader`'s own `read_byte_record_impl`.
+        loop {
+            let (result, nin, nout, nend) = self.core.read_record(
+                &input[consumed..],
+                &mut self.field_buf[self.outlen..],
+                &mut self.ends_buf[self.endlen..],
+            );
+            // Scan only the bytes that led up to this result, so a blank
+            // line is applied to the record that follows it.
+            self.track_blank_lines(&input[consumed..consumed + nin]);
+            consumed += nin;
+            self.outlen += nout;
+            self.endlen += nend;
+
+            match result {
+                ReadRecordResult::InputEmpty | ReadRecordResult::End => break,
+                ReadRecordResult::OutputFull => {
+                    let len = self.field_buf.len();
+                    self.field_buf.resize(len * 2, 0);
+                }
+                ReadRecordResult::OutputEndsFull => {
+                    let len = self.ends_buf.len();
+                    self.ends_buf.resize(len * 2, 0);
+                }
+                ReadRecordResult::Record => {
+                    let record = self.take_record(self.endlen);
+                    self.emit(record);
+                    records += 1;
+                    self.outlen = 0;
+                    self.endlen = 0;
+                }
+            }
+        }
+
+        (records, consumed)
+    }
+
+    /// Scans newly-arrived bytes for a `\n\n` blank-line separator,
+    /// tracking the last byte seen across calls so a separator split
+    /// across two `decode` calls isn't missed. `csv_core::Reader` has no
+    /// way to surface this itself: it treats a blank line as routine
+    /// whitespace between records rather than a zero-field `Record`, so
+    /// detecting it has to happen on the raw bytes, alongside parsing
+    /// rather than through it.
+    fn track_blank_lines(&mut self, input: &[u8]) {
+        let mut prev = self.last_byte;
+        for &b in input {
+            if b == b'\n' && prev == b'\n' {
+                self.pending_reset = true;
+            }
+            prev = b;
+        }
+        if let Some(&last) = input.last() {
+            self.last_byte = last;
+        }
+    }
+
+    /// Builds a `StringRecord` out of the fields the core decoder just
+    /// wrote into `field_buf`/`ends_buf`. `field_buf`/`ends_buf` stay
+    /// allocated and are simply overwritten on the next record.
+    fn take_record(&self, nend: usize) -> csv::StringRecord {
+        let mut record = csv::StringRecord::new();
+        let mut start = 0;
+        for &end in &self.ends_buf[..nend] {
+            record.push_field(std::str::from_utf8(&self.field_buf[start..end]).unwrap_or(""));
+            start = end;
+        }
+        record
+    }
+
+    fn emit(&mut self, record: csv::StringRecord) {
+        if self.pending_reset {
+            // The old line-based reader started a fresh series (a new
+            // `Message::Data`) on a blank-line boundary, even in
+            // Incremental mode. Preserve that by dropping the inferred
+            // schema; this record re-derives it.
+            self.schema = None;
+            self.pending_reset = false;
+        }
+
+        match self.schema.take() {
+            None => {
+                let schema = Schema::new(
+                    self.x.clone(),
+                    self.epoch.clone(),
+                    &self.conversions,
+                    &record,
+                );
+                self.sender_to_main_loop
+                    .send(Message::Data(schema.empty_set()))
+                    .unwrap();
+                self.schema = Some(schema);
+            }
+            Some(schema) => {
+                let (slice, failures) = schema.slice(&record);
+                if failures > 0 {
+                    self.sender_to_main_loop
+                        .send(Message::ConversionError(failures))
+                        .unwrap();
+                }
+                self.sender_to_main_loop
+                    .send(Message::DataSlice(slice))
+                    .unwrap();
+                self.schema = Some(schema);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod incremental_decoder_tests {
+    use super::*;
+
+    fn drain(receiver: &mpsc::Receiver<Message>) -> Vec<Message> {
+        let mut out = vec![];
+        while let Ok(m) = receiver.try_recv() {
+            out.push(m);
+        }
+        out
+    }
+
+    #[test]
+    fn decode_survives_a_field_larger_than_the_initial_field_buffer() {
+        // field_buf starts at 4096 bytes; a single field past that has
+        // to grow the buffer mid-record without corrupting what a
+        // previous `read_record` call already wrote for this record.
+        let (sender, receiver) = mpsc::channel();
+        let mut decoder = IncrementalDecoder::new(Column::Index(1), Column::None, vec![], sender);
+
+        let long_value = "x".repeat(5000);
+        let input = format!("a,b\n1,{}\n", long_value);
+        let (records, consumed) = decoder.decode(input.as_bytes());
+        assert_eq!(consumed, input.len());
+        assert_eq!(records, 2);
+
+        let messages = drain(&receiver);
+        assert!(matches!(messages[0], Message::Data(_)));
+        match &messages[1] {
+            Message::DataSlice(slice) => assert_eq!(slice.x.as_deref(), Some(long_value.as_str())),
+            _ => panic!("expected a DataSlice"),
+        }
+    }
+
+    #[test]
+    fn decode_survives_a_row_with_more_fields_than_the_initial_ends_buffer() {
+        // ends_buf starts at 64 entries; a row with more fields than
+        // that has to grow mid-record the same way field_buf does.
+        let (sender, receiver) = mpsc::channel();
+        let mut decoder = IncrementalDecoder::new(Column::None, Column::None, vec![], sender);
+
+        let headers: Vec<String> = (0..100).map(|i| format!("c{}", i)).collect();
+        let values: Vec<String> = (0..100).map(|i| i.to_string()).collect();
+        let input = format!("{}\n{}\n", headers.join(","), values.join(","));
+
+        let (records, consumed) = decoder.decode(input.as_bytes());
+        assert_eq!(consumed, input.len());
+        assert_eq!(records, 2);
+
+        match &drain(&receiver)[1] {
+            Message::DataSlice(slice) => {
+                assert_eq!(slice.y.len(), 100);
+                assert_eq!(slice.y[99], 99.0);
+            }
+            _ => panic!("expected a DataSlice"),
+        }
+    }
+
+    #[test]
+    fn decode_resets_the_schema_on_a_blank_line() {
+        let (sender, receiver) = mpsc::channel();
+        let mut decoder = IncrementalDecoder::new(Column::None, Column::None, vec![], sender);
+
+        decoder.decode(b"a,b\n1,2\n\nc,d,e\n3,4,5\n");
+
+        let messages = drain(&receiver);
+        // "a,b" header, "1,2" row, then the blank line drops the schema
+        // so "c,d,e" is treated as a fresh header rather than data.
+        assert!(matches!(messages[0], Message::Data(_)));
+        assert!(matches!(messages[1], Message::DataSlice(_)));
+        match &messages[2] {
+            Message::Data(set) => assert_eq!(set.y.len(), 3),
+            _ => panic!("expected a fresh Data for the new schema"),
+        }
+        assert!(matches!(messages[3], Message::DataSlice(_)));
+    }
+
+    #[test]
+    fn decode_resets_the_schema_on_a_blank_line_split_across_calls() {
+        let (sender, receiver) = mpsc::channel();
+        let mut decoder = IncrementalDecoder::new(Column::None, Column::None, vec![], sender);
+
+        decoder.decode(b"a,b\n1,2\n");
+        decoder.decode(b"\nc,d\n3,4\n");
+
+        let messages = drain(&receiver);
+        match &messages[2] {
+            Message::Data(set) => assert_eq!(set.y.len(), 2),
+            _ => panic!("expected a fresh Data for the new schema"),
+        }
+    }
+}
+
+/// Poll-driven counterpart to `FetcherLoop`/`Fetcher`.
+///
+/// `FetcherLoop` dedicates an OS thread to a blocking read loop, which
+/// means the UI can't react to the data fd and a redraw/auto-refresh
+/// timer on the same loop, and there's no way to apply back-pressure or
+/// cancel a fetch in progress. `PollableFetcher` instead exposes the
+/// underlying source's `RawFd` so the main event loop can register it
+/// next to its own timers, following the usual fd-based event-loop
+/// integration pattern: call `poll_ready` once the fd is observed
+/// readable, and it consumes only the bytes currently available before
+/// returning control to the loop.
+#[cfg(unix)]
+pub struct PollableFetcher<R: Read + std::os::unix::io::AsRawFd> {
+    source: R,
+    decoder: IncrementalDecoder,
+    buf: Vec<u8>,
+    pending: usize,
+}
+
+#[cfg(unix)]
+impl<R: Read + std::os::unix::io::AsRawFd> PollableFetcher<R> {
+    pub fn new(
+        source: R,
+        x: Column,
+        epoch: Column,
+        conversions: Vec<String>,
+        sender_to_main_loop: mpsc::Sender<Message>,
+    ) -> PollableFetcher<R> {
+        PollableFetcher {
+            source,
+            decoder: IncrementalDecoder::new(x, epoch, conversions, sender_to_main_loop),
+            buf: vec![0u8; 8192],
+            pending: 0,
+        }
+    }
+
+    /// The fd to register with the main event loop, alongside its redraw
+    /// and auto-refresh timers.
+    pub fn as_raw_fd(&self) -> std::os::unix::io::RawFd {
+        self.source.as_raw_fd()
+    }
+
+    /// Consumes whatever bytes are currently available on the fd and
+    /// returns control; never blocks waiting for more. Intended to be
+    /// called by the main loop once `poll`/`epoll` reports the fd
+    /// readable. Returns the number of complete records emitted.
+    pub fn poll_ready(&mut self) -> Result<usize, FetcherError> {
+        if self.pending == self.buf.len() {
+            let len = self.buf.len();
+            self.buf.resize(len * 2, 0);
+        }
+        let n = self.source.read(&mut self.buf[self.pending..])?;
+        if n == 0 {
+            // Same EOF-flush as `read_lines`: release any trailing
+            // record the core decoder is still holding onto.
+            let (records, _) = self.decoder.decode(&[]);
+            return Ok(records);
+        }
+
+        let available = self.pending + n;
+        let (records, consumed) = self.decoder.decode(&self.buf[..available]);
+        self.buf.copy_within(consumed..available, 0);
+        self.pending = available - consumed;
+        Ok(records)
+    }
+}
+
+#[cfg(all(test, unix))]
+mod pollable_fetcher_tests {
+    use super::*;
+    use std::io::Write;
+    use std::os::unix::net::UnixStream;
+
+    fn drain(receiver: &mpsc::Receiver<Message>) -> Vec<Message> {
+        let mut out = vec![];
+        while let Ok(m) = receiver.try_recv() {
+            out.push(m);
+        }
+        out
+    }
+
+    #[test]
+    fn poll_ready_carries_a_partial_record_across_calls() {
+        let (mut tx, rx) = UnixStream::pair().unwrap();
+        let (sender, receiver) = mpsc::channel();
+        let mut fetcher = PollableFetcher::new(rx, Column::None, Column::None, vec![], sender);
+
+        tx.write_all(b"a,b\n1,2").unwrap();
+        // "a,b\n" is a complete record (the header); the trailing "1,2"
+        // has no terminator yet, so it's held inside the core decoder.
+        let records = fetcher.poll_ready().unwrap();
+        assert_eq!(records, 1);
+        assert!(matches!(drain(&receiver)[..], [Message::Data(_)]));
+
+        tx.write_all(b"\n3,4\n").unwrap();
+        // Completes the held-over "1,2" row and adds a full "3,4" row.
+        let records = fetcher.poll_ready().unwrap();
+        assert_eq!(records, 2);
+
+        let messages = drain(&receiver);
+        assert!(matches!(messages[0], Message::DataSlice(_)));
+        assert!(matches!(messages[1], Message::DataSlice(_)));
+    }
+
+    #[test]
+    fn poll_ready_flushes_the_trailing_record_at_eof() {
+        let (mut tx, rx) = UnixStream::pair().unwrap();
+        let (sender, receiver) = mpsc::channel();
+        let mut fetcher = PollableFetcher::new(rx, Column::None, Column::None, vec![], sender);
+
+        tx.write_all(b"a,b\n1,2").unwrap();
+        drop(tx); // close the write half so the next read observes EOF
+
+        // "a,b\n" (the header) is complete; "1,2" has no terminator.
+        let records = fetcher.poll_ready().unwrap();
+        assert_eq!(records, 1);
+        assert!(matches!(drain(&receiver)[..], [Message::Data(_)]));
+
+        // EOF: read() returns 0, but the unterminated "1,2" row is still
+        // flushed out of the core decoder instead of being dropped.
+        let records = fetcher.poll_ready().unwrap();
+        assert_eq!(records, 1);
+        assert!(matches!(drain(&receiver)[..], [Message::DataSlice(_)]));
+    }
+}
+
 #[derive(Debug)]
 pub enum FetcherError {
     IO(std::io::Error),
@@ -1,14 +1,130 @@
 use crate::app::settings::Column;
 use crate::data::series::{Series, SeriesSet, Slice};
 
+use chrono::{Local, TimeZone, Utc};
+use std::collections::HashMap;
+
 struct ColumnSchema {
     title: String,
     index: usize,
+    // strptime-style format for Column::Timestamp/TimestampTZFmt, if any.
+    fmt: Option<String>,
+    // whether `fmt` already carries a timezone offset (TimestampTZFmt).
+    tz_aware: bool,
 }
 
 impl ColumnSchema {
-    pub fn new(title: String, index: usize) -> ColumnSchema {
-        ColumnSchema { title, index }
+    pub fn new(title: String, index: usize, column: &Column) -> ColumnSchema {
+        let (fmt, tz_aware) = match column {
+            Column::Timestamp { fmt, .. } => (Some(fmt.clone()), false),
+            Column::TimestampTZFmt { fmt, .. } => (Some(fmt.clone()), true),
+            _ => (None, false),
+        };
+        ColumnSchema {
+            title,
+            index,
+            fmt,
+            tz_aware,
+        }
+    }
+}
+
+/// Parses a raw X/epoch field into a canonical number of seconds since
+/// epoch, using `fmt` if one was configured. Bare (non-timezone) formats
+/// are interpreted in local time, falling back to UTC if the value
+/// doesn't map to exactly one local time (a DST gap or overlap).
+/// A time-only format (no date component, e.g. `%H:%M:%S`) is anchored
+/// to today's local date. Returns `None` if there's no format to parse
+/// with, or the value doesn't match it; the raw string is always kept
+/// alongside this for display, never replaced by it.
+fn parse_timestamp(raw: &str, fmt: &Option<String>, tz_aware: bool) -> Option<f64> {
+    let fmt = fmt.as_ref()?;
+    let raw = raw.trim();
+
+    if tz_aware {
+        return chrono::DateTime::parse_from_str(raw, fmt)
+            .map(|dt| dt.timestamp() as f64)
+            .ok();
+    }
+
+    let naive = chrono::NaiveDateTime::parse_from_str(raw, fmt)
+        .or_else(|_| chrono::NaiveDate::parse_from_str(raw, fmt).map(|d| d.and_hms(0, 0, 0)))
+        .or_else(|_| {
+            chrono::NaiveTime::parse_from_str(raw, fmt)
+                .map(|t| Local::now().naive_local().date().and_time(t))
+        })
+        .ok()?;
+    let timestamp = Local
+        .from_local_datetime(&naive)
+        .single()
+        .map(|dt| dt.timestamp())
+        .unwrap_or_else(|| Utc.from_utc_datetime(&naive).timestamp());
+    Some(timestamp as f64)
+}
+
+/// Per-column type conversion applied to a y series when slicing a row.
+/// Modeled after the conversion types found in similar data-pipeline
+/// configs, so that integer counters and booleans don't collapse into
+/// lossy floats or silent NaNs the way a bare `parse::<f64>()` does.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Conversion {
+    /// Parse the raw field as a number, as-is (same parsing as `Float`,
+    /// which is the default for unconfigured columns).
+    Bytes,
+    Integer,
+    Float,
+    Boolean,
+    /// RFC 3339 timestamp, converted to seconds since epoch.
+    Timestamp,
+    /// Timestamp in a custom `chrono` strptime format, converted to
+    /// seconds since epoch.
+    TimestampFmt(String),
+}
+
+impl Conversion {
+    /// Parses a single `field=type` specifier, e.g. `"latency=float"` or
+    /// `"seen_at=timestamp:%Y-%m-%d %H:%M:%S"`.
+    pub fn parse(spec: &str) -> Option<(String, Conversion)> {
+        let mut parts = spec.splitn(2, '=');
+        let field = parts.next()?.trim();
+        let kind = parts.next()?.trim();
+        if field.is_empty() || kind.is_empty() {
+            return None;
+        }
+
+        let conversion = match kind {
+            "bytes" | "as-is" => Conversion::Bytes,
+            "int" | "integer" => Conversion::Integer,
+            "float" => Conversion::Float,
+            "bool" | "boolean" => Conversion::Boolean,
+            "timestamp" => Conversion::Timestamp,
+            _ if kind.starts_with("timestamp:") => {
+                Conversion::TimestampFmt(kind["timestamp:".len()..].to_owned())
+            }
+            _ => return None,
+        };
+        Some((field.to_owned(), conversion))
+    }
+
+    /// Converts a raw field value into its `f64` series representation,
+    /// failing instead of silently producing NaN on a malformed value.
+    fn convert(&self, raw: &str) -> Result<f64, ()> {
+        let raw = raw.trim();
+        match self {
+            Conversion::Bytes | Conversion::Float => raw.parse::<f64>().map_err(|_| ()),
+            Conversion::Integer => raw.parse::<i64>().map(|v| v as f64).map_err(|_| ()),
+            Conversion::Boolean => match raw.to_ascii_lowercase().as_str() {
+                "true" | "1" | "yes" => Ok(1.0),
+                "false" | "0" | "no" => Ok(0.0),
+                _ => Err(()),
+            },
+            Conversion::Timestamp => chrono::DateTime::parse_from_rfc3339(raw)
+                .map(|dt| dt.timestamp() as f64)
+                .map_err(|_| ()),
+            Conversion::TimestampFmt(fmt) => chrono::NaiveDateTime::parse_from_str(raw, fmt)
+                .map(|dt| dt.timestamp() as f64)
+                .map_err(|_| ()),
+        }
     }
 }
 
@@ -21,6 +137,8 @@ pub struct Schema {
     epoch: Option<ColumnSchema>,
     // titles should be also stored here.
     titles: Vec<String>,
+    // one conversion per entry in `titles`, same order.
+    conversions: Vec<Conversion>,
 }
 
 impl Schema {
@@ -29,22 +147,31 @@ impl Schema {
             x: None,
             epoch: None,
             titles: vec![],
+            conversions: vec![],
         }
     }
     /// Creates new schema instance, using x/epoch configuration and
-    /// titles from the input.
-    pub fn new<'a, I>(x: Column, epoch: Column, titles: I) -> Schema
-    where
-        I: Iterator<Item = &'a str>,
-    {
+    /// titles from the input. `conversions` is a list of `field=type`
+    /// specifiers (see `Conversion::parse`); columns with no matching
+    /// specifier default to `Conversion::Float`.
+    pub fn new(
+        x: Column,
+        epoch: Column,
+        conversions: &[String],
+        titles: &csv::StringRecord,
+    ) -> Schema {
         let mut res = Schema::default();
+        let specs: HashMap<String, Conversion> =
+            conversions.iter().filter_map(|s| Conversion::parse(s)).collect();
 
-        titles.zip(0..).for_each(|(t, i)| {
+        titles.iter().enumerate().for_each(|(i, t)| {
             if x.matches(t, i) {
-                res.x = Some(ColumnSchema::new(t.to_owned(), i));
+                res.x = Some(ColumnSchema::new(t.to_owned(), i, &x));
             } else if epoch.matches(t, i) {
-                res.epoch = Some(ColumnSchema::new(t.to_owned(), i));
+                res.epoch = Some(ColumnSchema::new(t.to_owned(), i, &epoch));
             } else {
+                res.conversions
+                    .push(specs.get(t).cloned().unwrap_or(Conversion::Float));
                 res.titles.push(t.to_owned());
             }
         });
@@ -65,21 +192,74 @@ impl Schema {
         }
     }
 
-    /// Formats a row of input data as a slice.
-    /// Slice can be appended to a SeriesSet.
-    pub fn slice<'a, I>(&self, input: I) -> Slice
-    where
-        I: Iterator<Item = &'a str>,
-    {
+    /// Formats a row of input data as a slice, converting each y field
+    /// according to its configured `Conversion`. Returns the slice along
+    /// with the number of fields that failed to convert (reported as NaN
+    /// in the slice itself, but the caller should surface the failure
+    /// count rather than let it pass silently).
+    ///
+    /// Allocates a fresh `Slice` every call; kept as a thin wrapper
+    /// around `slice_into` for tests and one-off callers. Hot loops over
+    /// many rows should keep a scratch `Slice` and call `slice_into`
+    /// directly instead.
+    pub fn slice(&self, record: &csv::StringRecord) -> (Slice, usize) {
         let mut res = Slice::default();
-        input
+        let failures = self.slice_into(record, &mut res);
+        (res, failures)
+    }
+
+    /// Like `slice`, but clears and refills a caller-owned `Slice`
+    /// instead of allocating a new one. Reusing the same `Slice` (and
+    /// its `y` buffer's capacity) across rows avoids a heap allocation
+    /// per row on large batches.
+    ///
+    /// Note this only covers the per-row scratch `Slice`; it does not
+    /// pre-size the per-series accumulation vectors inside the
+    /// `SeriesSet` that `append_slice` grows on every row.
+    ///
+    /// `x`/`epoch` always keep the raw field text, for display; when a
+    /// `fmt` is configured, the canonical seconds-since-epoch value is
+    /// additionally parsed into `x_value`/`epoch_value` for sorting,
+    /// bucketing and plotting on a real time axis.
+    pub fn slice_into(&self, record: &csv::StringRecord, out: &mut Slice) -> usize {
+        out.x = None;
+        out.x_value = None;
+        out.epoch = None;
+        out.epoch_value = None;
+        out.y.clear();
+
+        let mut failures = 0;
+        let mut y_index = 0;
+        record
+            .iter()
             .enumerate()
             .for_each(|(i, v)| match (&self.x, &self.epoch) {
-                (Some(x), _) if x.index == i => res.x = Some(v.to_owned()),
-                (_, Some(e)) if e.index == i => res.epoch = Some(v.to_owned()),
-                _ => res.y.push(v.trim().parse::<f64>().unwrap_or(std::f64::NAN)),
+                (Some(x), _) if x.index == i => {
+                    out.x = Some(v.to_owned());
+                    out.x_value = parse_timestamp(v, &x.fmt, x.tz_aware);
+                }
+                (_, Some(e)) if e.index == i => {
+                    out.epoch = Some(v.to_owned());
+                    out.epoch_value = parse_timestamp(v, &e.fmt, e.tz_aware);
+                }
+                _ => {
+                    let conversion = self.conversions.get(y_index).unwrap_or(&Conversion::Float);
+                    match conversion.convert(v) {
+                        Ok(n) => out.y.push(n),
+                        Err(_) => {
+                            failures += 1;
+                            out.y.push(std::f64::NAN);
+                        }
+                    }
+                    y_index += 1;
+                }
             });
-        res
+        failures
+    }
+
+    /// Number of y (non-X/epoch) series, used to size scratch buffers.
+    pub fn y_len(&self) -> usize {
+        self.titles.len()
     }
 }
 #[cfg(test)]
@@ -91,7 +271,8 @@ mod tests {
         let schema = Schema::new(
             Column::None,
             Column::None,
-            vec!["a", "b", "c"].iter().map(|s| *s),
+            &[],
+            &csv::StringRecord::from(vec!["a", "b", "c"]),
         );
         let s = schema.empty_set();
         assert_eq!(s.epoch, None);
@@ -101,10 +282,11 @@ mod tests {
         assert_eq!(s.y[1].title, "b");
         assert_eq!(s.y[2].title, "c");
 
-        let slice = schema.slice(vec!["1", "2", "3"].iter().map(|s| *s));
+        let (slice, failures) = schema.slice(&csv::StringRecord::from(vec!["1", "2", "3"]));
         assert_eq!(slice.epoch, None);
         assert_eq!(slice.x, None);
         assert_eq!(slice.y, vec![1.0, 2.0, 3.0]);
+        assert_eq!(failures, 0);
     }
 
     #[test]
@@ -112,7 +294,8 @@ mod tests {
         let schema = Schema::new(
             Column::Index(0),
             Column::Title("b".to_owned()),
-            vec!["a", "b", "c"].iter().map(|s| *s),
+            &[],
+            &csv::StringRecord::from(vec!["a", "b", "c"]),
         );
         let s = schema.empty_set();
         assert_eq!(s.epoch, None);
@@ -120,9 +303,79 @@ mod tests {
         assert_eq!(s.y.len(), 1);
         assert_eq!(s.y[0].title, "c");
 
-        let slice = schema.slice(vec!["1", "2", "3"].iter().map(|s| *s));
+        let (slice, failures) = schema.slice(&csv::StringRecord::from(vec!["1", "2", "3"]));
         assert_eq!(slice.epoch, Some("2".to_owned()));
         assert_eq!(slice.x, Some("1".to_owned()));
         assert_eq!(slice.y, vec![3.0]);
+        assert_eq!(failures, 0);
+    }
+
+    #[test]
+    fn test_quoted_fields() {
+        // A quoted field containing a comma must stay a single value,
+        // not be split into two.
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader("a,\"b, with a comma\",c".as_bytes());
+        let record = reader.records().next().unwrap().unwrap();
+
+        let schema = Schema::new(Column::None, Column::None, &[], &record);
+        let s = schema.empty_set();
+        assert_eq!(s.y.len(), 3);
+        assert_eq!(s.y[1].title, "b, with a comma");
+    }
+
+    #[test]
+    fn test_conversions() {
+        let schema = Schema::new(
+            Column::None,
+            Column::None,
+            &["count=int".to_owned(), "ok=bool".to_owned()],
+            &csv::StringRecord::from(vec!["count", "ok", "rate"]),
+        );
+
+        let (slice, failures) = schema.slice(&csv::StringRecord::from(vec!["3", "not-a-bool", "1.5"]));
+        assert_eq!(slice.y[0], 3.0);
+        assert!(slice.y[1].is_nan());
+        assert_eq!(slice.y[2], 1.5);
+        assert_eq!(failures, 1);
+    }
+
+    #[test]
+    fn test_x_timestamp() {
+        let schema = Schema::new(
+            Column::Timestamp {
+                index: 0,
+                fmt: "%Y-%m-%d".to_owned(),
+            },
+            Column::None,
+            &[],
+            &csv::StringRecord::from(vec!["day", "value"]),
+        );
+
+        let (slice, _) = schema.slice(&csv::StringRecord::from(vec!["2021-01-01", "1"]));
+        // x keeps the raw string for display...
+        assert_eq!(slice.x, Some("2021-01-01".to_owned()));
+        // ...while x_value holds the canonical number of seconds since epoch.
+        assert!(slice.x_value.is_some());
+    }
+
+    #[test]
+    fn test_x_bare_time() {
+        // A time-only format has no date component; it should anchor to
+        // today's date rather than fail and fall back to the raw string.
+        let schema = Schema::new(
+            Column::Timestamp {
+                index: 0,
+                fmt: "%H:%M:%S".to_owned(),
+            },
+            Column::None,
+            &[],
+            &csv::StringRecord::from(vec!["time", "value"]),
+        );
+
+        let (slice, _) = schema.slice(&csv::StringRecord::from(vec!["12:30:00", "1"]));
+        assert_eq!(slice.x, Some("12:30:00".to_owned()));
+        assert!(slice.x_value.is_some());
     }
 }